@@ -0,0 +1,96 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::Router;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::Arc;
+
+use crate::errors::{OreMinerError, Result};
+use crate::is_process_running;
+use crate::sinks::PrometheusSink;
+
+/// Shared handle the supervisor uses to publish the PID of the `ore` child
+/// it currently has running. `/health` reads this to report liveness of the
+/// actual miner rather than the supervisor daemon's own PID, which is alive
+/// for as long as the daemon is, even while the child is crash-looping or
+/// sitting in a restart backoff sleep.
+#[derive(Clone, Default)]
+pub struct ChildPidTracker(Arc<AtomicI32>);
+
+impl ChildPidTracker {
+    pub fn new() -> Self {
+        Self(Arc::new(AtomicI32::new(0)))
+    }
+
+    /// Records the PID of a freshly spawned child.
+    pub fn set(&self, pid: i32) {
+        self.0.store(pid, Ordering::SeqCst);
+    }
+
+    /// Clears the tracked PID once the child has exited.
+    pub fn clear(&self) {
+        self.0.store(0, Ordering::SeqCst);
+    }
+
+    pub(crate) fn get(&self) -> Option<i32> {
+        match self.0.load(Ordering::SeqCst) {
+            0 => None,
+            pid => Some(pid),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct AdminState {
+    registry: Arc<PrometheusSink>,
+    child_pid: ChildPidTracker,
+}
+
+/// Runs the embedded admin HTTP server, exposing `/metrics` in Prometheus
+/// text format and `/health` for process-manager liveness checks. Runs
+/// until the process exits or the listener errors out.
+pub async fn serve(
+    addr: SocketAddr,
+    registry: Arc<PrometheusSink>,
+    child_pid: ChildPidTracker,
+) -> Result<()> {
+    let state = AdminState {
+        registry,
+        child_pid,
+    };
+    let app = Router::new()
+        .route("/metrics", get(metrics_handler))
+        .route("/health", get(health_handler))
+        .with_state(state);
+
+    tracing::info!("Admin server listening on {}", addr);
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .map_err(OreMinerError::Io)?;
+
+    axum::serve(listener, app)
+        .await
+        .map_err(|e| OreMinerError::Daemon(format!("Admin server error: {}", e)))
+}
+
+async fn metrics_handler(State(state): State<AdminState>) -> impl IntoResponse {
+    state.registry.render()
+}
+
+async fn health_handler(State(state): State<AdminState>) -> impl IntoResponse {
+    match state.child_pid.get() {
+        Some(pid) if is_process_running(pid) => {
+            (StatusCode::OK, format!("ok pid={}\n", pid))
+        }
+        Some(pid) => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            format!("dead pid={}\n", pid),
+        ),
+        None => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "no child process running\n".to_string(),
+        ),
+    }
+}
@@ -0,0 +1,184 @@
+use serde::Deserialize;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::errors::{OreMinerError, Result};
+use crate::{StartArgs, STANDALONE_BINARY_NAME};
+
+/// On-disk configuration loaded from `--config <path>` (TOML). Every field
+/// is optional here; a `StartArgs` is folded on top of it following the
+/// precedence CLI flag > environment variable > config-file value >
+/// built-in default (see `StartArgs::resolve`).
+#[derive(Debug, Deserialize, Default)]
+pub struct Config {
+    #[serde(default)]
+    pub miner: MinerConfig,
+    #[serde(default)]
+    pub cloudwatch: CloudWatchConfig,
+    #[serde(default)]
+    pub sinks: SinksConfig,
+    #[serde(default)]
+    pub admin: AdminConfig,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct MinerConfig {
+    pub cores: Option<String>,
+    pub keypair: Option<String>,
+    pub fee_payer: Option<String>,
+    pub dynamic_fee: Option<bool>,
+    pub dynamic_fee_url: Option<String>,
+    pub rpc: Option<String>,
+    pub ore_binary_path: Option<String>,
+    pub max_restart_delay: Option<u64>,
+    pub max_restarts: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct CloudWatchConfig {
+    pub region: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct SinksConfig {
+    pub cloudwatch_enabled: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct AdminConfig {
+    pub addr: Option<SocketAddr>,
+}
+
+impl Config {
+    /// Loads and parses a TOML config file from disk.
+    fn load(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path).map_err(OreMinerError::Io)?;
+        toml::from_str(&contents).map_err(|e| {
+            OreMinerError::Config(format!("Failed to parse config file {:?}: {}", path, e))
+        })
+    }
+}
+
+/// Fully resolved settings `async_main` and friends run with, after folding
+/// CLI flags, env vars, an optional config file, and built-in defaults.
+#[derive(Debug, Clone)]
+pub struct MiningConfig {
+    pub cores: String,
+    pub keypair: String,
+    pub fee_payer: String,
+    pub dynamic_fee: bool,
+    pub dynamic_fee_url: String,
+    pub rpc: String,
+    pub ore_binary_path: String,
+    pub admin_addr: Option<SocketAddr>,
+    pub max_restart_delay: u64,
+    pub max_restarts: u32,
+    pub cloudwatch_enabled: bool,
+    pub cloudwatch_region: Option<String>,
+}
+
+impl StartArgs {
+    /// Folds this CLI invocation with its `--config` file (if any),
+    /// environment variables, and built-in defaults into a `MiningConfig`,
+    /// then validates that every required field ended up set.
+    pub(crate) fn resolve(self) -> Result<MiningConfig> {
+        let config = match &self.config {
+            Some(path) => Config::load(path)?,
+            None => Config::default(),
+        };
+
+        let cores = resolve_required(self.cores, "ORE_MINER_CORES", config.miner.cores, "cores")?;
+        let keypair = resolve_required(
+            self.keypair,
+            "ORE_MINER_KEYPAIR",
+            config.miner.keypair,
+            "keypair",
+        )?;
+        let rpc = resolve_required(self.rpc, "ORE_MINER_RPC", config.miner.rpc, "rpc")?;
+
+        let fee_payer = resolve(
+            self.fee_payer,
+            "ORE_MINER_FEE_PAYER",
+            config.miner.fee_payer,
+            String::new(),
+        );
+        let dynamic_fee_url = resolve(
+            self.dynamic_fee_url,
+            "ORE_MINER_DYNAMIC_FEE_URL",
+            config.miner.dynamic_fee_url,
+            String::new(),
+        );
+        let dynamic_fee = self.dynamic_fee
+            || resolve(
+                None,
+                "ORE_MINER_DYNAMIC_FEE",
+                config.miner.dynamic_fee,
+                false,
+            );
+        let ore_binary_path = resolve(
+            self.ore_binary_path,
+            "ORE_MINER_BINARY_PATH",
+            config.miner.ore_binary_path,
+            STANDALONE_BINARY_NAME.to_string(),
+        );
+        let admin_addr = resolve_optional(self.admin_addr, "ORE_MINER_ADMIN_ADDR", config.admin.addr);
+        let max_restart_delay = resolve(
+            self.max_restart_delay,
+            "ORE_MINER_MAX_RESTART_DELAY",
+            config.miner.max_restart_delay,
+            60,
+        );
+        let max_restarts = resolve(
+            self.max_restarts,
+            "ORE_MINER_MAX_RESTARTS",
+            config.miner.max_restarts,
+            0,
+        );
+        let cloudwatch_enabled = resolve(
+            None,
+            "ORE_MINER_CLOUDWATCH_ENABLED",
+            config.sinks.cloudwatch_enabled,
+            true,
+        );
+        let cloudwatch_region = resolve_optional(None, "AWS_ACCESS_REGION", config.cloudwatch.region);
+
+        Ok(MiningConfig {
+            cores,
+            keypair,
+            fee_payer,
+            dynamic_fee,
+            dynamic_fee_url,
+            rpc,
+            ore_binary_path,
+            admin_addr,
+            max_restart_delay,
+            max_restarts,
+            cloudwatch_enabled,
+            cloudwatch_region,
+        })
+    }
+}
+
+fn resolve<T: FromStr>(cli: Option<T>, env_key: &str, config: Option<T>, default: T) -> T {
+    resolve_optional(cli, env_key, config).unwrap_or(default)
+}
+
+fn resolve_optional<T: FromStr>(cli: Option<T>, env_key: &str, config: Option<T>) -> Option<T> {
+    cli.or_else(|| std::env::var(env_key).ok().and_then(|v| v.parse().ok()))
+        .or(config)
+}
+
+fn resolve_required<T: FromStr>(
+    cli: Option<T>,
+    env_key: &str,
+    config: Option<T>,
+    field_name: &str,
+) -> Result<T> {
+    resolve_optional(cli, env_key, config).ok_or_else(|| {
+        OreMinerError::Config(format!(
+            "Missing required field `{}` (set it via --{}, the {} environment variable, or a config file)",
+            field_name, field_name, env_key
+        ))
+    })
+}
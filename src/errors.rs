@@ -26,6 +26,9 @@ pub enum OreMinerError {
 
     #[error("Parse error: {0}")]
     ParseError(String),
+
+    #[error("Configuration error: {0}")]
+    Config(String),
 }
 
 /**
@@ -45,6 +48,7 @@ impl Clone for OreMinerError {
             Self::BinaryNotFound(s1, s2) => Self::BinaryNotFound(s1.clone(), s2.clone()),
             Self::EnvVar(s) => Self::EnvVar(s.clone()),
             Self::ParseError(s) => Self::ParseError(s.clone()),
+            Self::Config(s) => Self::Config(s.clone()),
         }
     }
 }
@@ -62,6 +66,7 @@ impl PartialEq for OreMinerError {
             (Self::BinaryNotFound(s1, s2), Self::BinaryNotFound(s3, s4)) => s1 == s3 && s2 == s4,
             (Self::EnvVar(s1), Self::EnvVar(s2)) => s1 == s2,
             (Self::ParseError(s1), Self::ParseError(s2)) => s1 == s2,
+            (Self::Config(s1), Self::Config(s2)) => s1 == s2,
             _ => core::mem::discriminant(self) == core::mem::discriminant(other),
         }
     }
@@ -1,19 +1,26 @@
-mod cloudwatch;
+mod admin;
+mod config;
 mod errors;
+mod metrics;
+mod sinks;
 
 extern crate dotenv;
-use aws_sdk_cloudwatch::Client;
 use chrono::Local;
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use daemonize::Daemonize;
 use dotenv::dotenv;
 use std::env;
 use std::fs::{create_dir_all, remove_file, File};
-use std::io::{BufRead, BufReader, Read};
-use std::path::Path;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::path::{Path, PathBuf};
 use std::process::{Child, Command, Stdio};
+use std::sync::Arc;
 use std::thread::JoinHandle;
+use std::time::Instant;
 use tokio::runtime::Runtime;
+use tokio::signal::unix::{signal, SignalKind};
+use tokio::sync::mpsc;
 use tokio::time::sleep;
 use tokio::time::Duration;
 use tracing::Level;
@@ -22,32 +29,88 @@ use tracing_subscriber::fmt;
 use tracing_subscriber::fmt::writer::MakeWriterExt;
 use which::which;
 
+use config::MiningConfig;
 use errors::{OreMinerError, Result};
+use metrics::Metrics;
+use sinks::{CloudWatchSink, MetricsSink, PrometheusSink};
 
 pub const DAEMON_FILE_PATH: &str = "/tmp/ore_miner";
 pub const STANDALONE_BINARY_NAME: &str = "ore";
 
+const STOP_TIMEOUT: Duration = Duration::from_secs(10);
+const STOP_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+const INITIAL_RESTART_DELAY: Duration = Duration::from_secs(1);
+const STABILITY_THRESHOLD: Duration = Duration::from_secs(60);
+
+const METRICS_CHANNEL_CAPACITY: usize = 1024;
+const METRICS_BATCH_SIZE: usize = 50;
+const METRICS_FLUSH_INTERVAL: Duration = Duration::from_secs(10);
+
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None)]
-struct Args {
+struct Cli {
+    #[clap(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand, Debug)]
+enum Commands {
+    /// Start the mining daemon
+    Start(StartArgs),
+    /// Stop the running daemon
+    Stop,
+    /// Report whether the daemon is running
+    Status(StatusArgs),
+    /// Stop the daemon (if running) and start it again
+    Restart(StartArgs),
+}
+
+#[derive(clap::Args, Debug)]
+pub(crate) struct StartArgs {
+    #[clap(
+        long,
+        help = "Path to a TOML config file. CLI flags and env vars still take precedence over its values."
+    )]
+    config: Option<PathBuf>,
     #[clap(long, help = "Number of CPU cores to use")]
-    cores: String,
+    cores: Option<String>,
     #[clap(long, help = "Path to your keypair file")]
-    keypair: String,
+    keypair: Option<String>,
     #[clap(long, help = "Path to your fee payer file")]
-    fee_payer: String,
+    fee_payer: Option<String>,
     #[clap(long, help = "Enable dynamic fees")]
     dynamic_fee: bool,
     #[clap(long, help = "URL to your dynamic fee RPC")]
-    dynamic_fee_url: String,
+    dynamic_fee_url: Option<String>,
     #[clap(long, help = "URL to your RPC")]
-    rpc: String,
+    rpc: Option<String>,
+    #[clap(long, help = "Path to the ore cli binary. Will default to \"ore\".")]
+    ore_binary_path: Option<String>,
     #[clap(
         long,
-        help = "Path to the ore cli binary. Will default to \"ore\".",
-        default_value = STANDALONE_BINARY_NAME
+        help = "Address to bind the admin HTTP server to (serves /metrics and /health). Disabled when not set here, in the environment, or in the config file."
     )]
-    ore_binary_path: String,
+    admin_addr: Option<SocketAddr>,
+    #[clap(
+        long,
+        help = "Maximum delay, in seconds, between child-process restarts"
+    )]
+    max_restart_delay: Option<u64>,
+    #[clap(
+        long,
+        help = "Maximum number of times to restart the child process before giving up (0 = unlimited)"
+    )]
+    max_restarts: Option<u32>,
+}
+
+#[derive(clap::Args, Debug)]
+struct StatusArgs {
+    #[clap(
+        long,
+        help = "Admin HTTP address to query for last-known metrics, e.g. 127.0.0.1:9184. Omit to skip."
+    )]
+    admin_addr: Option<SocketAddr>,
 }
 
 fn ensure_dir_exists(path: &str) -> Result<()> {
@@ -75,23 +138,89 @@ fn main() {
 fn run() -> Result<()> {
     dotenv().ok();
     ensure_dir_exists(DAEMON_FILE_PATH)?;
+    setup_logging()?;
 
-    let args = Args::parse();
+    let cli = Cli::parse();
+
+    match cli.command {
+        Commands::Start(args) => cmd_start(args),
+        Commands::Stop => cmd_stop(),
+        Commands::Status(args) => cmd_status(args),
+        Commands::Restart(args) => cmd_restart(args),
+    }
+}
+
+fn pid_file_path() -> String {
+    format!("{}/process.pid", DAEMON_FILE_PATH)
+}
+
+fn cmd_start(args: StartArgs) -> Result<()> {
+    let config = args.resolve()?;
 
-    setup_logging()?;
     // alert the user based on stderr and then exit the program?
-    ensure_binary_exists(&args.ore_binary_path)?;
+    ensure_binary_exists(&config.ore_binary_path)?;
 
-    let pid_file_path = format!("{}/process.pid", DAEMON_FILE_PATH);
-    handle_existing_daemon(&pid_file_path)?;
+    ensure_not_already_running(&pid_file_path())?;
 
     start_daemon()?;
 
     let runtime = setup_runtime()?;
-    runtime.block_on(async_main(args))
+    runtime.block_on(async_main(config))
     // runtime.block_on(async_main_test());
 }
 
+fn cmd_stop() -> Result<()> {
+    match stop_daemon(&pid_file_path()) {
+        Ok(_) => Ok(()),
+        Err(OreMinerError::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+            println!("ore-miner daemon is not running");
+            Ok(())
+        }
+        Err(e) => Err(e),
+    }
+}
+
+fn cmd_restart(args: StartArgs) -> Result<()> {
+    match stop_daemon(&pid_file_path()) {
+        Ok(_) => {}
+        Err(OreMinerError::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+            tracing::info!("No existing daemon found; starting fresh.");
+        }
+        Err(e) => return Err(e),
+    }
+
+    cmd_start(args)
+}
+
+fn cmd_status(args: StatusArgs) -> Result<()> {
+    match read_pid_file(&pid_file_path()) {
+        Ok(pid) if is_process_running(pid) => {
+            println!("ore-miner daemon is running (pid {})", pid);
+            match process_uptime(pid) {
+                Ok(uptime) => println!("uptime: {}s", uptime.as_secs()),
+                Err(e) => tracing::warn!("Failed to determine uptime for pid {}: {:?}", pid, e),
+            }
+
+            if let Some(addr) = args.admin_addr {
+                match fetch_last_known_metrics(addr) {
+                    Ok(metrics) => println!("last-known metrics:\n{}", metrics),
+                    Err(e) => tracing::warn!("Failed to fetch metrics from {}: {:?}", addr, e),
+                }
+            }
+        }
+        Ok(pid) => println!(
+            "ore-miner daemon is not running (stale PID file, last pid {})",
+            pid
+        ),
+        Err(OreMinerError::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+            println!("ore-miner daemon is not running");
+        }
+        Err(e) => return Err(e),
+    }
+
+    Ok(())
+}
+
 #[allow(dead_code)]
 async fn async_main_test() {
     let mut count = 0;
@@ -150,26 +279,31 @@ fn ensure_binary_exists(binary_path: &str) -> Result<()> {
     Ok(())
 }
 
-fn handle_existing_daemon(pid_file_path: &str) -> Result<()> {
-    let mut file = File::open(pid_file_path).map_err(|e| OreMinerError::Io(e))?;
+fn read_pid_file(pid_file_path: &str) -> Result<i32> {
+    let mut file = File::open(pid_file_path).map_err(OreMinerError::Io)?;
     let mut pid = String::new();
-    file.read_to_string(&mut pid)
-        .map_err(|e| OreMinerError::Io(e))?;
+    file.read_to_string(&mut pid).map_err(OreMinerError::Io)?;
+    pid.trim().parse().map_err(OreMinerError::PidParse)
+}
 
-    let pid: i32 = pid.trim().parse().map_err(|e| OreMinerError::PidParse(e))?;
-    if is_process_running(pid) {
-        tracing::info!("Daemon is already running. Stopping it first.");
-        stop_daemon(pid_file_path)
-            .map_err(|e| OreMinerError::Daemon(format!("Unable to stop existing daemon: {}", e)))?;
-    } else {
-        tracing::info!("Removing stale PID file.");
-        remove_file(pid_file_path).map_err(|e| OreMinerError::Io(e))?;
+/// Returns an error if the daemon is already running; cleans up a stale
+/// PID file left behind by a process that died without stopping cleanly.
+fn ensure_not_already_running(pid_file_path: &str) -> Result<()> {
+    match read_pid_file(pid_file_path) {
+        Ok(pid) if is_process_running(pid) => Err(OreMinerError::Daemon(format!(
+            "Daemon is already running (pid {}). Use `restart` to relaunch it.",
+            pid
+        ))),
+        Ok(_) => {
+            tracing::info!("Removing stale PID file.");
+            remove_file(pid_file_path).map_err(OreMinerError::Io)
+        }
+        Err(OreMinerError::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e),
     }
-
-    Ok(())
 }
 
-fn is_process_running(pid: i32) -> bool {
+pub(crate) fn is_process_running(pid: i32) -> bool {
     std::process::Command::new("kill")
         .arg("-0")
         .arg(pid.to_string())
@@ -178,22 +312,150 @@ fn is_process_running(pid: i32) -> bool {
         .unwrap_or(false)
 }
 
+/// Sends SIGTERM and waits up to `STOP_TIMEOUT` for the process to exit,
+/// escalating to SIGKILL if it doesn't.
 fn stop_daemon(pid_file_path: &str) -> Result<()> {
-    let mut file = File::open(pid_file_path)?;
-    let mut pid = String::new();
-    file.read_to_string(&mut pid)?;
-    let pid: i32 = pid.trim().parse()?;
+    let pid = read_pid_file(pid_file_path)?;
+
+    if !is_process_running(pid) {
+        tracing::info!(
+            "No running process for pid {}. Removing stale PID file.",
+            pid
+        );
+        return remove_file(pid_file_path).map_err(OreMinerError::Io);
+    }
+
+    tracing::info!("Sending SIGTERM to pid {}", pid);
+    send_signal(pid, "-TERM")?;
+
+    let mut waited = Duration::from_secs(0);
+    while is_process_running(pid) && waited < STOP_TIMEOUT {
+        std::thread::sleep(STOP_POLL_INTERVAL);
+        waited += STOP_POLL_INTERVAL;
+    }
+
+    if is_process_running(pid) {
+        tracing::warn!(
+            "Process {} did not exit within {:?} of SIGTERM, sending SIGKILL",
+            pid,
+            STOP_TIMEOUT
+        );
+        send_signal(pid, "-KILL")?;
+    }
+
+    tracing::info!("Daemon stopped successfully");
+    remove_file(pid_file_path).map_err(OreMinerError::Io)
+}
+
+/// Waits for the daemon to receive SIGTERM (as sent by `stop_daemon`) and
+/// forwards it to the currently tracked `ore` child, escalating to SIGKILL
+/// if it doesn't exit within `STOP_TIMEOUT`. Without this, the daemon's
+/// default SIGTERM disposition would kill only the supervisor, leaving the
+/// child reparented to PID 1 and mining on unsupervised. Exits the daemon
+/// process once the child has exited or has been sent SIGKILL.
+async fn forward_sigterm_to_child(child_pid: admin::ChildPidTracker) -> Result<()> {
+    let mut term_signals = signal(SignalKind::terminate()).map_err(OreMinerError::Io)?;
+
+    term_signals.recv().await;
+    tracing::info!("Daemon received SIGTERM; forwarding to ore child before exiting");
+
+    if let Some(pid) = child_pid.get() {
+        if is_process_running(pid) {
+            send_signal(pid, "-TERM")?;
+
+            let mut waited = Duration::from_secs(0);
+            while is_process_running(pid) && waited < STOP_TIMEOUT {
+                sleep(STOP_POLL_INTERVAL).await;
+                waited += STOP_POLL_INTERVAL;
+            }
+
+            if is_process_running(pid) {
+                tracing::warn!(
+                    "Child process {} did not exit within {:?} of SIGTERM, sending SIGKILL",
+                    pid,
+                    STOP_TIMEOUT
+                );
+                send_signal(pid, "-KILL")?;
+            }
+        }
+    }
+
+    tracing::info!("ore child terminated; daemon exiting");
+    std::process::exit(0);
+}
 
+fn send_signal(pid: i32, signal: &str) -> Result<()> {
     std::process::Command::new("kill")
+        .arg(signal)
         .arg(pid.to_string())
         .status()
-        .map_err(|e| OreMinerError::Daemon(format!("Failed to stop daemon: {}", e)))?;
+        .map_err(|e| {
+            OreMinerError::Daemon(format!("Failed to send {} to pid {}: {}", signal, pid, e))
+        })?;
 
-    tracing::info!("Daemon stopped successfully");
+    Ok(())
+}
 
-    std::fs::remove_file(pid_file_path)?;
+/// Reads `/proc/<pid>` to compute how long the process has been running.
+fn process_uptime(pid: i32) -> Result<Duration> {
+    let stat = std::fs::read_to_string(format!("/proc/{}/stat", pid)).map_err(OreMinerError::Io)?;
+    let after_comm = stat
+        .rsplit_once(')')
+        .map(|(_, rest)| rest)
+        .ok_or_else(|| OreMinerError::ParseError(format!("Unexpected /proc/{}/stat format", pid)))?;
+
+    let fields: Vec<&str> = after_comm.split_whitespace().collect();
+    let starttime_ticks: u64 = fields
+        .get(19)
+        .ok_or_else(|| OreMinerError::ParseError("Missing starttime field".to_string()))?
+        .parse()
+        .map_err(|_| OreMinerError::ParseError("Failed to parse starttime".to_string()))?;
+
+    const CLOCK_TICKS_PER_SEC: u64 = 100;
+    let process_start_secs = starttime_ticks / CLOCK_TICKS_PER_SEC;
+
+    let uptime = std::fs::read_to_string("/proc/uptime").map_err(OreMinerError::Io)?;
+    let system_uptime_secs: f64 = uptime
+        .split_whitespace()
+        .next()
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| OreMinerError::ParseError("Failed to parse /proc/uptime".to_string()))?;
+
+    Ok(Duration::from_secs(
+        (system_uptime_secs as u64).saturating_sub(process_start_secs),
+    ))
+}
 
-    Ok(())
+/// Timeout for both connecting to and reading from the admin server when
+/// fetching last-known metrics, so a wedged admin server can't hang a
+/// command whose whole point is a quick liveness check.
+const METRICS_FETCH_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Issues a bare-bones HTTP GET against the admin server's `/metrics`
+/// endpoint so `status` can report the daemon's last-known metrics.
+fn fetch_last_known_metrics(addr: SocketAddr) -> Result<String> {
+    let mut stream =
+        TcpStream::connect_timeout(&addr, METRICS_FETCH_TIMEOUT).map_err(OreMinerError::Io)?;
+    stream
+        .set_read_timeout(Some(METRICS_FETCH_TIMEOUT))
+        .map_err(OreMinerError::Io)?;
+    let request = format!(
+        "GET /metrics HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n\r\n",
+        addr
+    );
+    stream
+        .write_all(request.as_bytes())
+        .map_err(OreMinerError::Io)?;
+
+    let mut response = String::new();
+    stream
+        .read_to_string(&mut response)
+        .map_err(OreMinerError::Io)?;
+
+    Ok(response
+        .split_once("\r\n\r\n")
+        .map(|(_, body)| body.to_string())
+        .unwrap_or(response))
 }
 
 fn start_daemon() -> Result<()> {
@@ -242,46 +504,181 @@ fn setup_runtime() -> Result<Runtime> {
         .map_err(|e| OreMinerError::Io(e.into()))
 }
 
-async fn async_main(args: Args) -> Result<()> {
-    let client = cloudwatch::create_cloudwatch_client().await?;
-    let mut command = build_command(&args);
+async fn async_main(config: MiningConfig) -> Result<()> {
+    let (sinks, prometheus_registry) = build_sinks(&config).await?;
+    let child_pid = admin::ChildPidTracker::new();
 
-    let mut child = spawn_child_process(&mut command)?;
+    {
+        let child_pid = child_pid.clone();
+        tokio::spawn(async move {
+            if let Err(e) = forward_sigterm_to_child(child_pid).await {
+                tracing::error!("Signal forwarding task exited with error: {:?}", e);
+            }
+        });
+    }
 
-    let (stdout_handle, stderr_handle) = spawn_output_handlers(&mut child, &client)?;
+    if let (Some(addr), Some(registry)) = (config.admin_addr, prometheus_registry) {
+        let child_pid = child_pid.clone();
+        tokio::spawn(async move {
+            if let Err(e) = admin::serve(addr, registry, child_pid).await {
+                tracing::error!("Admin server exited with error: {:?}", e);
+            }
+        });
+    }
 
-    let status = child
-        .wait()
-        .map_err(|e| OreMinerError::CommandExecution(e.to_string()))?;
-    tracing::info!("CLI tool exited with status: {}", status);
+    run_supervised(&config, sinks, child_pid).await
+}
 
-    stdout_handle.join().expect("Failed to join stdout thread");
-    stderr_handle.join().expect("Failed to join stderr thread");
+/// Runs the `ore` child process under supervision, restarting it with
+/// capped exponential backoff whenever it exits non-zero or fails to
+/// spawn. The backoff resets once a run has stayed up longer than
+/// `STABILITY_THRESHOLD`. Gives up and returns an error after
+/// `config.max_restarts` restarts (0 = unlimited).
+async fn run_supervised(
+    config: &MiningConfig,
+    sinks: Arc<Vec<Box<dyn MetricsSink>>>,
+    child_pid: admin::ChildPidTracker,
+) -> Result<()> {
+    let max_restart_delay = Duration::from_secs(config.max_restart_delay);
+    let mut restart_delay = INITIAL_RESTART_DELAY;
+    let mut restart_count: u64 = 0;
 
-    Ok(())
+    loop {
+        let mut command = build_command(config);
+        let started_at = Instant::now();
+
+        let run_result = match spawn_child_process(&mut command) {
+            Ok(mut child) => {
+                child_pid.set(child.id() as i32);
+
+                let outcome = match spawn_output_handlers(&mut child) {
+                    Ok((stdout_handle, stderr_handle, metrics_rx)) => {
+                        let batch_handle =
+                            tokio::spawn(drain_metrics(metrics_rx, Arc::clone(&sinks)));
+
+                        let status = child
+                            .wait()
+                            .map_err(|e| OreMinerError::CommandExecution(e.to_string()));
+
+                        stdout_handle.join().expect("Failed to join stdout thread");
+                        stderr_handle.join().expect("Failed to join stderr thread");
+                        batch_handle
+                            .await
+                            .expect("Metrics batching task panicked");
+
+                        status
+                    }
+                    Err(e) => Err(e),
+                };
+
+                child_pid.clear();
+
+                outcome
+            }
+            Err(e) => Err(e),
+        };
+
+        match run_result {
+            Ok(status) if status.success() => {
+                tracing::info!("CLI tool exited with status: {}", status);
+                return Ok(());
+            }
+            Ok(status) => tracing::error!("CLI tool exited with non-zero status: {}", status),
+            Err(e) => tracing::error!("Failed to run CLI tool: {:?}", e),
+        }
+
+        if started_at.elapsed() >= STABILITY_THRESHOLD {
+            tracing::info!(
+                "Child process ran for {:?} before exiting; resetting restart backoff",
+                started_at.elapsed()
+            );
+            restart_delay = INITIAL_RESTART_DELAY;
+        }
+
+        restart_count += 1;
+        report_restart_count(&sinks, restart_count).await;
+
+        if config.max_restarts != 0 && restart_count > config.max_restarts as u64 {
+            return Err(OreMinerError::CommandExecution(format!(
+                "Child process failed {} times, exceeding --max-restarts={}; giving up",
+                restart_count, config.max_restarts
+            )));
+        }
+
+        tracing::info!(
+            "Restarting child process in {:?} (restart #{})",
+            restart_delay,
+            restart_count
+        );
+        sleep(restart_delay).await;
+        restart_delay = std::cmp::min(restart_delay * 2, max_restart_delay);
+    }
+}
+
+/// Fans the current restart count out to every sink as a `Metrics` update,
+/// the same way parsed mining metrics are reported.
+async fn report_restart_count(sinks: &[Box<dyn MetricsSink>], restart_count: u64) {
+    let mut metrics = Metrics::new();
+    metrics.restart_count = Some(restart_count);
+
+    for sink in sinks {
+        if let Err(e) = sink.send(&metrics).await {
+            tracing::error!(
+                "Error sending restart-count metric to {}: {:?}",
+                sink.name(),
+                e
+            );
+        }
+    }
 }
 
-fn build_command(args: &Args) -> Command {
-    let mut command = Command::new(&args.ore_binary_path);
+/// Builds the set of `MetricsSink`s that parsed mining metrics are fanned
+/// out to, plus the `PrometheusSink` registry (if the admin server is
+/// enabled) so `async_main` can hand it to `admin::serve` for scraping.
+/// CloudWatch is added unless `cloudwatch_enabled` is turned off; future
+/// sinks (statsd, ...) are appended here behind their own config flags.
+async fn build_sinks(
+    config: &MiningConfig,
+) -> Result<(Arc<Vec<Box<dyn MetricsSink>>>, Option<Arc<PrometheusSink>>)> {
+    let mut sinks: Vec<Box<dyn MetricsSink>> = Vec::new();
+    if config.cloudwatch_enabled {
+        sinks.push(Box::new(
+            CloudWatchSink::new(config.cloudwatch_region.clone()).await?,
+        ));
+    }
+
+    let prometheus_registry = if config.admin_addr.is_some() {
+        let registry = Arc::new(PrometheusSink::new());
+        sinks.push(Box::new(Arc::clone(&registry)));
+        Some(registry)
+    } else {
+        None
+    };
+
+    Ok((Arc::new(sinks), prometheus_registry))
+}
+
+fn build_command(config: &MiningConfig) -> Command {
+    let mut command = Command::new(&config.ore_binary_path);
     command
         .arg("mine")
         .arg("--cores")
-        .arg(&args.cores)
+        .arg(&config.cores)
         .arg("--keypair")
-        .arg(&args.keypair)
+        .arg(&config.keypair)
         .arg("--rpc")
-        .arg(&args.rpc);
+        .arg(&config.rpc);
 
-    if !args.fee_payer.is_empty() {
-        command.arg("--fee-payer").arg(&args.fee_payer);
+    if !config.fee_payer.is_empty() {
+        command.arg("--fee-payer").arg(&config.fee_payer);
     }
 
-    if args.dynamic_fee {
+    if config.dynamic_fee {
         command.arg("--dynamic-fee");
     }
 
-    if !args.dynamic_fee_url.is_empty() {
-        command.arg("--dynamic-fee-url").arg(&args.dynamic_fee_url);
+    if !config.dynamic_fee_url.is_empty() {
+        command.arg("--dynamic-fee-url").arg(&config.dynamic_fee_url);
     }
 
     command
@@ -295,10 +692,13 @@ fn spawn_child_process(command: &mut Command) -> Result<Child> {
         .map_err(|e| OreMinerError::CommandExecution(e.to_string()))
 }
 
+/// Spawns the stdout/stderr reader threads for `child`. The stdout thread
+/// only parses lines into `Metrics` and pushes them onto the returned
+/// channel; sending to sinks happens on the main Tokio runtime via
+/// `drain_metrics`, so this never needs its own `Runtime`.
 fn spawn_output_handlers(
     child: &mut Child,
-    client: &Client,
-) -> Result<(JoinHandle<()>, JoinHandle<()>)> {
+) -> Result<(JoinHandle<()>, JoinHandle<()>, mpsc::Receiver<Metrics>)> {
     let stdout = child.stdout.take().ok_or(OreMinerError::CommandExecution(
         "Failed to capture stdout".to_string(),
     ))?;
@@ -306,12 +706,26 @@ fn spawn_output_handlers(
         "Failed to capture stderr".to_string(),
     ))?;
 
-    let cloudwatch_client = client.clone();
+    let (tx, rx) = mpsc::channel(METRICS_CHANNEL_CAPACITY);
+
     let stdout_handle = std::thread::spawn(move || {
-        let rt: Runtime = Runtime::new().unwrap();
         let reader = BufReader::new(stdout);
         for line in reader.lines().flatten() {
-            rt.block_on(process_output(&line, &cloudwatch_client));
+            tracing::info!("processing line: {}", line);
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            match metrics::parse_metrics(&line) {
+                Ok(parsed) => {
+                    tracing::info!("Parsed metrics: {:?}", parsed);
+                    if tx.blocking_send(parsed).is_err() {
+                        tracing::error!("Metrics channel closed; dropping remaining output");
+                        break;
+                    }
+                }
+                Err(e) => tracing::error!("Error: {:?}", e),
+            }
         }
     });
 
@@ -322,13 +736,52 @@ fn spawn_output_handlers(
         }
     });
 
-    Ok((stdout_handle, stderr_handle))
+    Ok((stdout_handle, stderr_handle, rx))
 }
 
-async fn process_output(line: &str, client: &Client) {
-    tracing::info!("processing line: {}", line);
-    match cloudwatch::process_mining_metrics(client, line).await {
-        Ok(_) => tracing::info!("Successfully sent metrics to CloudWatch"),
-        Err(e) => tracing::error!("Error: {:?}", e),
+/// Drains parsed `Metrics` off `rx`, flushing the accumulated batch to
+/// every sink when it hits `METRICS_BATCH_SIZE` or `METRICS_FLUSH_INTERVAL`
+/// elapses, whichever comes first. Returns once the stdout thread drops
+/// its sender and the channel drains.
+async fn drain_metrics(mut rx: mpsc::Receiver<Metrics>, sinks: Arc<Vec<Box<dyn MetricsSink>>>) {
+    let mut batch = Vec::with_capacity(METRICS_BATCH_SIZE);
+    let mut interval = tokio::time::interval(METRICS_FLUSH_INTERVAL);
+    interval.tick().await; // first tick fires immediately; consume it so flushes stay on schedule
+
+    loop {
+        tokio::select! {
+            received = rx.recv() => {
+                match received {
+                    Some(parsed) => {
+                        batch.push(parsed);
+                        if batch.len() >= METRICS_BATCH_SIZE {
+                            flush_batch(&sinks, &mut batch).await;
+                        }
+                    }
+                    None => {
+                        flush_batch(&sinks, &mut batch).await;
+                        return;
+                    }
+                }
+            }
+            _ = interval.tick() => {
+                flush_batch(&sinks, &mut batch).await;
+            }
+        }
+    }
+}
+
+async fn flush_batch(sinks: &[Box<dyn MetricsSink>], batch: &mut Vec<Metrics>) {
+    if batch.is_empty() {
+        return;
     }
+
+    for sink in sinks {
+        match sink.send_batch(batch).await {
+            Ok(_) => tracing::info!("Flushed {} metrics to {}", batch.len(), sink.name()),
+            Err(e) => tracing::error!("Error flushing metrics to {}: {:?}", sink.name(), e),
+        }
+    }
+
+    batch.clear();
 }
@@ -0,0 +1,98 @@
+use crate::errors::{OreMinerError, Result};
+
+/// Represents the metrics collected during the mining process.
+#[derive(Debug, Clone)]
+pub struct Metrics {
+    pub stake: Option<f64>,
+    pub change: Option<f64>,
+    pub multiplier: Option<f64>,
+    pub difficulty: Option<u64>,
+    pub timestamp: Option<String>,
+    pub tx_hash: Option<String>,
+    /// Number of times the supervisor has restarted the child `ore`
+    /// process. Set by the supervision loop, not by `parse_metrics`.
+    pub restart_count: Option<u64>,
+}
+
+impl Metrics {
+    /// Creates a new instance of Metrics with all fields set to None.
+    pub fn new() -> Self {
+        Self {
+            stake: None,
+            change: None,
+            multiplier: None,
+            difficulty: None,
+            timestamp: None,
+            tx_hash: None,
+            restart_count: None,
+        }
+    }
+}
+
+/// Parses a single line of `ore` CLI output into a `Metrics` struct.
+pub fn parse_metrics(line: &str) -> Result<Metrics> {
+    let trimmed_line = line.trim();
+    tracing::debug!("Parsing metrics from line: {:?}", trimmed_line);
+
+    let mut metrics = Metrics::new();
+    let parts: Vec<&str> = trimmed_line.split_whitespace().collect();
+
+    match parts.get(0) {
+        Some(&"Stake:") => metrics.stake = parse_float(&parts, 1)?,
+        Some(&"Change:") => metrics.change = parse_float(&parts, 1)?,
+        Some(&"Multiplier:") => metrics.multiplier = parse_multiplier(&parts)?,
+        Some(&"Best") if parts.get(1) == Some(&"hash:") => {
+            metrics.difficulty = parse_difficulty(&parts)?
+        }
+        Some(&"Timestamp:") => metrics.timestamp = parse_timestamp(&parts)?,
+        Some(&"OK") => {
+            metrics.tx_hash = Some(
+                parts
+                    .get(1)
+                    .ok_or(OreMinerError::ParseError("Missing tx_hash".to_string()))?
+                    .to_string(),
+            )
+        }
+        _ => return Err(OreMinerError::ParseError("Unknown metric type".to_string())),
+    }
+
+    Ok(metrics)
+}
+
+fn parse_float(parts: &[&str], index: usize) -> Result<Option<f64>> {
+    parts
+        .get(index)
+        .ok_or_else(|| OreMinerError::ParseError("Missing value".to_string()))?
+        .parse()
+        .map(Some)
+        .map_err(|e| OreMinerError::ParseError(format!("Failed to parse float: {}", e)))
+}
+
+fn parse_multiplier(parts: &[&str]) -> Result<Option<f64>> {
+    parts
+        .get(1)
+        .ok_or_else(|| OreMinerError::ParseError("Missing multiplier value".to_string()))?
+        .trim_end_matches('x')
+        .parse()
+        .map(Some)
+        .map_err(|e| OreMinerError::ParseError(format!("Failed to parse multiplier: {}", e)))
+}
+
+fn parse_difficulty(parts: &[&str]) -> Result<Option<u64>> {
+    parts
+        .get(4)
+        .ok_or_else(|| OreMinerError::ParseError("Missing difficulty value".to_string()))?
+        .trim_end_matches(')')
+        .parse()
+        .map(Some)
+        .map_err(|e| OreMinerError::ParseError(format!("Failed to parse difficulty: {}", e)))
+}
+
+fn parse_timestamp(parts: &[&str]) -> Result<Option<String>> {
+    if parts.len() < 3 {
+        return Err(OreMinerError::ParseError(
+            "Invalid timestamp format".to_string(),
+        ));
+    }
+    Ok(Some(format!("{}T{}Z", parts[1], parts[2])))
+}
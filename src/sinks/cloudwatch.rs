@@ -0,0 +1,172 @@
+use aws_config::meta::region::RegionProviderChain;
+use aws_sdk_cloudwatch::config::Credentials;
+use aws_sdk_cloudwatch::types::{Dimension, MetricDatum, StandardUnit};
+use aws_sdk_cloudwatch::{Client, Error};
+use aws_types::region::Region;
+use async_trait::async_trait;
+
+use crate::errors::{OreMinerError, Result};
+use crate::metrics::Metrics;
+use crate::sinks::MetricsSink;
+
+/// CloudWatch's `PutMetricData` accepts at most this many datums per request.
+const MAX_DATUMS_PER_REQUEST: usize = 1000;
+
+/// A `MetricsSink` that publishes parsed mining metrics to CloudWatch.
+pub struct CloudWatchSink {
+    client: Client,
+}
+
+impl CloudWatchSink {
+    /// Builds a `CloudWatchSink`, creating its CloudWatch client from
+    /// `region_override` (falling back to `AWS_ACCESS_REGION`) plus the
+    /// `AWS_ACCESS_KEY_ID`/`AWS_SECRET_ACCESS_KEY` environment variables.
+    pub async fn new(region_override: Option<String>) -> Result<Self> {
+        let client = create_cloudwatch_client(region_override).await?;
+        Ok(Self { client })
+    }
+}
+
+#[async_trait]
+impl MetricsSink for CloudWatchSink {
+    async fn send(&self, metrics: &Metrics) -> Result<()> {
+        self.send_batch(std::slice::from_ref(metrics)).await
+    }
+
+    async fn send_batch(&self, metrics: &[Metrics]) -> Result<()> {
+        send_metrics_to_cloudwatch(&self.client, metrics)
+            .await
+            .map_err(|e| {
+                OreMinerError::CloudWatch(format!("Failed to send metrics to CloudWatch: {}", e))
+            })
+    }
+
+    fn name(&self) -> &str {
+        "cloudwatch"
+    }
+}
+
+async fn create_cloudwatch_client(region_override: Option<String>) -> Result<Client> {
+    let region = region_override.or_else(|| std::env::var("AWS_ACCESS_REGION").ok());
+    let region_provider = RegionProviderChain::first_try(region.map(Region::new))
+        .or_default_provider()
+        .or_else(Region::new("us-east-1"));
+
+    let access_key = std::env::var("AWS_ACCESS_KEY_ID")
+        .map_err(|_| OreMinerError::EnvVar("AWS_ACCESS_KEY_ID not set".to_string()))?;
+    let secret_key = std::env::var("AWS_SECRET_ACCESS_KEY")
+        .map_err(|_| OreMinerError::EnvVar("AWS_SECRET_ACCESS_KEY not set".to_string()))?;
+
+    let credentials = Credentials::new(access_key, secret_key, None, None, "ore-miner-credentials");
+
+    let config = aws_config::from_env()
+        .region(region_provider)
+        .credentials_provider(credentials)
+        .load()
+        .await;
+
+    let client = Client::new(&config);
+    tracing::info!("Created CloudWatch client");
+
+    Ok(client)
+}
+
+/// Coalesces a batch of `Metrics` into as few `PutMetricData` calls as
+/// possible instead of one request per datum, chunking to respect
+/// CloudWatch's per-request datum limit.
+async fn send_metrics_to_cloudwatch(
+    client: &Client,
+    metrics: &[Metrics],
+) -> std::result::Result<(), Error> {
+    let common_dimensions = vec![Dimension::builder()
+        .name("Environment")
+        .value("MainnetBeta")
+        .build()];
+
+    let metric_data: Vec<MetricDatum> = metrics
+        .iter()
+        .flat_map(|m| build_metric_data_for(m, &common_dimensions))
+        .collect();
+
+    if metric_data.is_empty() {
+        tracing::info!("No metrics to report to CloudWatch");
+        return Ok(());
+    }
+
+    for chunk in metric_data.chunks(MAX_DATUMS_PER_REQUEST) {
+        send_chunk(client, chunk).await;
+    }
+
+    Ok(())
+}
+
+fn build_metric_data_for(metrics: &Metrics, dimensions: &[Dimension]) -> Vec<MetricDatum> {
+    vec![
+        build_metric_datum("Stake", metrics.stake, dimensions),
+        build_metric_datum("Change", metrics.change, dimensions),
+        build_metric_datum("Multiplier", metrics.multiplier, dimensions),
+        build_metric_datum("Difficulty", metrics.difficulty.map(|d| d as f64), dimensions),
+        build_metric_datum(
+            "RestartCount",
+            metrics.restart_count.map(|c| c as f64),
+            dimensions,
+        ),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn build_metric_datum(
+    name: &str,
+    value: Option<f64>,
+    dimensions: &[Dimension],
+) -> Option<MetricDatum> {
+    value.map(|v| {
+        MetricDatum::builder()
+            .metric_name(name)
+            .set_dimensions(Some(dimensions.to_vec()))
+            .value(v)
+            .unit(StandardUnit::None)
+            .build()
+    })
+}
+
+/// Sends one chunk of datums. CloudWatch rejects the whole request if any
+/// single datum is invalid, so on failure we retry one datum at a time and
+/// only drop (and log) the offending ones, instead of losing the batch.
+async fn send_chunk(client: &Client, chunk: &[MetricDatum]) {
+    let result = client
+        .put_metric_data()
+        .namespace("OreMining")
+        .set_metric_data(Some(chunk.to_vec()))
+        .send()
+        .await;
+
+    match result {
+        Ok(_) => tracing::info!("Sent {} metrics to CloudWatch", chunk.len()),
+        Err(e) => {
+            tracing::error!(
+                "Batch of {} metrics rejected by CloudWatch ({}), retrying individually",
+                chunk.len(),
+                e
+            );
+            for datum in chunk {
+                let result = client
+                    .put_metric_data()
+                    .namespace("OreMining")
+                    .set_metric_data(Some(vec![datum.clone()]))
+                    .send()
+                    .await;
+
+                if let Err(e) = result {
+                    tracing::error!(
+                        "Dropping metric datum {:?}: {}",
+                        datum.metric_name(),
+                        e
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,50 @@
+mod cloudwatch;
+mod prometheus;
+
+pub use cloudwatch::CloudWatchSink;
+pub use prometheus::PrometheusSink;
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::errors::Result;
+use crate::metrics::Metrics;
+
+/// A destination that parsed mining `Metrics` can be fanned out to.
+///
+/// Implementations own whatever client/connection they need and are
+/// responsible for translating `Metrics` into their backend's shape.
+#[async_trait]
+pub trait MetricsSink: Send + Sync {
+    /// Sends a single parsed metrics line to this sink.
+    async fn send(&self, metrics: &Metrics) -> Result<()>;
+
+    /// Sends a batch of parsed metrics to this sink. The default
+    /// implementation just calls `send` once per entry; sinks whose
+    /// backend supports a bulk API (e.g. CloudWatch's `PutMetricData`)
+    /// should override this to coalesce the batch into fewer requests.
+    async fn send_batch(&self, metrics: &[Metrics]) -> Result<()> {
+        for m in metrics {
+            self.send(m).await?;
+        }
+        Ok(())
+    }
+
+    /// Human-readable identifier used in logging.
+    fn name(&self) -> &str;
+}
+
+#[async_trait]
+impl<T: MetricsSink + ?Sized> MetricsSink for Arc<T> {
+    async fn send(&self, metrics: &Metrics) -> Result<()> {
+        (**self).send(metrics).await
+    }
+
+    async fn send_batch(&self, metrics: &[Metrics]) -> Result<()> {
+        (**self).send_batch(metrics).await
+    }
+
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+}
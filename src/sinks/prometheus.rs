@@ -0,0 +1,109 @@
+use async_trait::async_trait;
+use std::sync::RwLock;
+
+use crate::errors::Result;
+use crate::metrics::Metrics;
+use crate::sinks::MetricsSink;
+
+/// Latest known value for each gauge the admin server exposes.
+#[derive(Default)]
+struct Gauges {
+    stake: Option<f64>,
+    change: Option<f64>,
+    multiplier: Option<f64>,
+    difficulty: Option<u64>,
+    last_tx_hash: Option<String>,
+    restart_count: Option<u64>,
+}
+
+/// A `MetricsSink` that keeps the latest gauge values in memory so they can
+/// be served in Prometheus/OpenMetrics text format on scrape by the `admin`
+/// module, rather than pushed to a remote backend.
+pub struct PrometheusSink {
+    gauges: RwLock<Gauges>,
+}
+
+impl PrometheusSink {
+    pub fn new() -> Self {
+        Self {
+            gauges: RwLock::new(Gauges::default()),
+        }
+    }
+
+    /// Renders the current gauge values in Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let gauges = self.gauges.read().unwrap();
+        let mut out = String::new();
+
+        if let Some(stake) = gauges.stake {
+            out.push_str("# HELP ore_miner_stake Current stake reported by the miner.\n");
+            out.push_str("# TYPE ore_miner_stake gauge\n");
+            out.push_str(&format!("ore_miner_stake {}\n", stake));
+        }
+        if let Some(change) = gauges.change {
+            out.push_str("# HELP ore_miner_change Stake change reported by the miner.\n");
+            out.push_str("# TYPE ore_miner_change gauge\n");
+            out.push_str(&format!("ore_miner_change {}\n", change));
+        }
+        if let Some(multiplier) = gauges.multiplier {
+            out.push_str("# HELP ore_miner_multiplier Current reward multiplier.\n");
+            out.push_str("# TYPE ore_miner_multiplier gauge\n");
+            out.push_str(&format!("ore_miner_multiplier {}\n", multiplier));
+        }
+        if let Some(difficulty) = gauges.difficulty {
+            out.push_str("# HELP ore_miner_difficulty Difficulty of the best hash found.\n");
+            out.push_str("# TYPE ore_miner_difficulty gauge\n");
+            out.push_str(&format!("ore_miner_difficulty {}\n", difficulty));
+        }
+        if let Some(tx_hash) = &gauges.last_tx_hash {
+            out.push_str(
+                "# HELP ore_miner_last_tx_hash_info Last submitted transaction hash, as a label.\n",
+            );
+            out.push_str("# TYPE ore_miner_last_tx_hash_info gauge\n");
+            out.push_str(&format!(
+                "ore_miner_last_tx_hash_info{{tx_hash=\"{}\"}} 1\n",
+                tx_hash
+            ));
+        }
+        if let Some(restart_count) = gauges.restart_count {
+            out.push_str(
+                "# HELP ore_miner_restart_count Number of times the supervisor has restarted the child process.\n",
+            );
+            out.push_str("# TYPE ore_miner_restart_count counter\n");
+            out.push_str(&format!("ore_miner_restart_count {}\n", restart_count));
+        }
+
+        out
+    }
+}
+
+#[async_trait]
+impl MetricsSink for PrometheusSink {
+    async fn send(&self, metrics: &Metrics) -> Result<()> {
+        let mut gauges = self.gauges.write().unwrap();
+        if metrics.stake.is_some() {
+            gauges.stake = metrics.stake;
+        }
+        if metrics.change.is_some() {
+            gauges.change = metrics.change;
+        }
+        if metrics.multiplier.is_some() {
+            gauges.multiplier = metrics.multiplier;
+        }
+        if metrics.difficulty.is_some() {
+            gauges.difficulty = metrics.difficulty;
+        }
+        if metrics.tx_hash.is_some() {
+            gauges.last_tx_hash = metrics.tx_hash.clone();
+        }
+        if metrics.restart_count.is_some() {
+            gauges.restart_count = metrics.restart_count;
+        }
+
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "prometheus"
+    }
+}